@@ -0,0 +1,181 @@
+//! Groups imported bank lines into per-period budget summaries.
+//!
+//! The functions here consume the [Iterator](std::iter::Iterator) of
+//! [BankLine](crate::import::BankLine)s produced by
+//! [from_boursobank_csv](crate::import::from_boursobank_csv) and fold it into a
+//! [PeriodSummary] per calendar month or ISO week.
+
+use std::collections::BTreeMap;
+
+use crate::import::BankLine;
+use crate::units::{Amount, Date, Month, Weekday};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Aggregated figures for every operation of a single period.
+pub struct PeriodSummary {
+    /// Sum of the positive operations (money received).
+    inflow: Amount,
+    /// Sum of the negative operations, as a positive magnitude (money spent).
+    outflow: Amount,
+    /// Net change over the period (`inflow - outflow`).
+    net: Amount,
+    /// Number of operations.
+    count: usize,
+    /// Net subtotal per category, keyed by the full parent-to-son category path.
+    category_subtotals: BTreeMap<Vec<String>, Amount>,
+}
+
+impl PeriodSummary {
+    /// Builds an empty summary, ready to be fed operations.
+    fn empty() -> Self {
+        Self {
+            inflow: Amount::euro(0.0),
+            outflow: Amount::euro(0.0),
+            net: Amount::euro(0.0),
+            count: 0,
+            category_subtotals: BTreeMap::new(),
+        }
+    }
+
+    /// Folds one operation into the running totals.
+    fn accumulate(&mut self, line: &BankLine) {
+        let value = line.get_amount().as_euro();
+        if value >= 0.0 {
+            self.inflow = Amount::euro(self.inflow.as_euro() + value);
+        } else {
+            self.outflow = Amount::euro(self.outflow.as_euro() - value);
+        }
+        self.net = Amount::euro(self.net.as_euro() + value);
+        self.count += 1;
+
+        let subtotal = self
+            .category_subtotals
+            .entry(line.get_category().clone())
+            .or_insert_with(|| Amount::euro(0.0));
+        *subtotal = Amount::euro(subtotal.as_euro() + value);
+    }
+
+    /// Gets the total money received over the period.
+    pub fn get_inflow(&self) -> Amount {
+        self.inflow
+    }
+    /// Gets the total money spent over the period (as a positive magnitude).
+    pub fn get_outflow(&self) -> Amount {
+        self.outflow
+    }
+    /// Gets the net change over the period.
+    pub fn get_net(&self) -> Amount {
+        self.net
+    }
+    /// Gets the number of operations in the period.
+    pub fn get_count(&self) -> usize {
+        self.count
+    }
+    /// Gets the net subtotal of each category, keyed by category path.
+    pub fn get_category_subtotals(&self) -> &BTreeMap<Vec<String>, Amount> {
+        &self.category_subtotals
+    }
+}
+
+/// Groups operations by the calendar month of their operation date.
+///
+/// # Example
+/// ```
+/// use std::io::Cursor;
+/// use money_monitor::units::Month;
+/// use money_monitor::import::from_boursobank_csv;
+/// use money_monitor::aggregate::group_by_month;
+///
+/// let csv = Cursor::new(
+/// "dateOp;dateVal;label;category;categoryParent;supplierFound;amount;comment;accountNum;accountLabel;accountbalance
+/// 2025-08-26;2025-08-26;\"FOO\";\"Bar\";\"Bar\";\"BAZ\";-101,00;;42;BoursoBank;1057.24
+/// 2025-08-27;2025-08-27;\"BAR\";\"Bar\";\"Bar\";\"BAZ\";200,00;;42;BoursoBank;1257.24
+/// 2025-09-01;2025-09-01;\"BAZ\";\"Bar\";\"Bar\";\"BAZ\";-50,00;;42;BoursoBank;1207.24");
+///
+/// let summaries = group_by_month(from_boursobank_csv(csv));
+/// let august = &summaries[&(2025, Month::August)];
+/// assert_eq!(2, august.get_count());
+/// assert_eq!(200.00, august.get_inflow().as_euro());
+/// assert_eq!(101.00, august.get_outflow().as_euro());
+/// assert_eq!(99.00, august.get_net().as_euro());
+/// assert_eq!(1, summaries[&(2025, Month::September)].get_count());
+/// ```
+pub fn group_by_month<I: Iterator<Item = BankLine>>(
+    iter: I,
+) -> BTreeMap<(u16, Month), PeriodSummary> {
+    let mut map = BTreeMap::new();
+    for line in iter {
+        let date = line.get_date_op();
+        map.entry((date.year(), date.month()))
+            .or_insert_with(PeriodSummary::empty)
+            .accumulate(&line);
+    }
+    map
+}
+
+/// Groups operations by the ISO week of their operation date.
+///
+/// Keys are `(iso_year, iso_week)` pairs. Note that the ISO year of a date near
+/// a year boundary may differ from its calendar year.
+///
+/// # Example
+/// ```
+/// use std::io::Cursor;
+/// use money_monitor::import::from_boursobank_csv;
+/// use money_monitor::aggregate::group_by_week;
+///
+/// let csv = Cursor::new(
+/// "dateOp;dateVal;label;category;categoryParent;supplierFound;amount;comment;accountNum;accountLabel;accountbalance
+/// 2025-09-08;2025-09-08;\"FOO\";\"Bar\";\"Bar\";\"BAZ\";-10,00;;42;BoursoBank;1000.00
+/// 2025-09-10;2025-09-10;\"BAR\";\"Bar\";\"Bar\";\"BAZ\";-20,00;;42;BoursoBank;980.00");
+///
+/// let summaries = group_by_week(from_boursobank_csv(csv));
+/// // Both operations fall in ISO week 37 of 2025.
+/// assert_eq!(2, summaries[&(2025, 37)].get_count());
+/// ```
+pub fn group_by_week<I: Iterator<Item = BankLine>>(iter: I) -> BTreeMap<(u16, u8), PeriodSummary> {
+    let mut map = BTreeMap::new();
+    for line in iter {
+        map.entry(iso_year_week(line.get_date_op()))
+            .or_insert_with(PeriodSummary::empty)
+            .accumulate(&line);
+    }
+    map
+}
+
+/// Computes the ISO-8601 `(year, week)` of a date.
+///
+/// Weeks start on Monday and the first week of a year is the one containing its
+/// first Thursday, so dates in early January or late December can belong to a
+/// neighbouring ISO year.
+fn iso_year_week(date: &Date) -> (u16, u8) {
+    let iso_dow = match date.weekday() {
+        Weekday::Monday => 1,
+        Weekday::Tuesday => 2,
+        Weekday::Wednesday => 3,
+        Weekday::Thursday => 4,
+        Weekday::Friday => 5,
+        Weekday::Saturday => 6,
+        Weekday::Sunday => 7,
+    };
+    let year = date.year();
+    let week = (date.day_of_year() as i32 - iso_dow + 10) / 7;
+
+    if week < 1 {
+        (year - 1, weeks_in_year(year - 1))
+    } else if week as u8 > weeks_in_year(year) {
+        (year + 1, 1)
+    } else {
+        (year, week as u8)
+    }
+}
+
+/// Number of ISO weeks (52 or 53) in a calendar year.
+fn weeks_in_year(year: u16) -> u8 {
+    let p = |y: u32| ((y + y / 4 - y / 100 + y / 400) % 7) as u8;
+    if p(year as u32) == 4 || p(year as u32 - 1) == 3 {
+        53
+    } else {
+        52
+    }
+}
@@ -166,6 +166,62 @@ where
         .filter_map(|x| x)
 }
 
+/// Sorts bank operations chronologically by their operation date.
+///
+/// Consumes an iterator of [BankLine]s (such as the one returned by
+/// [from_boursobank_csv]) and returns them collected in a [Vec], oldest first.
+///
+/// # Example
+/// ```
+/// use std::io::Cursor;
+/// use money_monitor::import::{from_boursobank_csv, sort_by_date};
+///
+/// let csv = Cursor::new(
+/// "dateOp;dateVal;label;category;categoryParent;supplierFound;amount;comment;accountNum;accountLabel;accountbalance
+/// 2025-08-27;2025-08-27;\"FOO\";\"Bar\";\"Bar\";\"BAZ\";-10,00;;42;BoursoBank;1000.00
+/// 2025-08-25;2025-08-25;\"BAR\";\"Bar\";\"Bar\";\"BAZ\";-20,00;;42;BoursoBank;1010.00");
+///
+/// let sorted = sort_by_date(from_boursobank_csv(csv));
+/// assert_eq!("FOO", sorted[1].get_label()); // the 27th comes after the 25th
+/// ```
+pub fn sort_by_date<I: Iterator<Item = BankLine>>(iter: I) -> Vec<BankLine> {
+    let mut lines: Vec<_> = iter.collect();
+    lines.sort_by(|a, b| a.get_date_op().cmp(b.get_date_op()));
+    lines
+}
+
+/// Keeps only the operations whose operation date falls inside `[from, to]`.
+///
+/// The interval is inclusive on both ends. The relative order of the kept
+/// lines is preserved.
+///
+/// # Example
+/// ```
+/// use std::io::Cursor;
+/// use money_monitor::units::{Date, Month};
+/// use money_monitor::import::{from_boursobank_csv, filter_range};
+///
+/// # fn main() -> Result<(), String> {
+/// let csv = Cursor::new(
+/// "dateOp;dateVal;label;category;categoryParent;supplierFound;amount;comment;accountNum;accountLabel;accountbalance
+/// 2025-08-20;2025-08-20;\"OLD\";\"Bar\";\"Bar\";\"BAZ\";-10,00;;42;BoursoBank;1000.00
+/// 2025-08-26;2025-08-26;\"KEEP\";\"Bar\";\"Bar\";\"BAZ\";-20,00;;42;BoursoBank;1010.00");
+///
+/// let from = Date::new(25, Month::August, 2025)?;
+/// let to = Date::new(31, Month::August, 2025)?;
+/// let kept = filter_range(from_boursobank_csv(csv), from, to);
+/// assert_eq!(1, kept.len());
+/// assert_eq!("KEEP", kept[0].get_label());
+/// # Ok(())}
+/// ```
+pub fn filter_range<I: Iterator<Item = BankLine>>(iter: I, from: Date, to: Date) -> Vec<BankLine> {
+    iter.filter(|line| {
+        let date = line.get_date_op();
+        *date >= from && *date <= to
+    })
+    .collect()
+}
+
 /// Reads a csv from Boursobank.
 ///
 /// Returns an [Iterator] of all the lines contained in the file.
@@ -13,6 +13,11 @@ impl Amount {
         Self { amount }
     }
 
+    /// Gives back the amount as a number of euros.
+    pub fn as_euro(&self) -> f64 {
+        self.amount
+    }
+
     /// Parses an amount in euro.
     /// 
     /// The input string must be just a float (no €) with a . as decimal separator.
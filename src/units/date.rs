@@ -1,12 +1,16 @@
 #[derive(Debug, Clone, PartialEq, Eq)]
 /// A date of the year
+///
+/// Dates are totally ordered: they are compared by year, then by month, then
+/// by day. This is what lets the [import](crate::import) module sort and
+/// range-filter a stream of bank operations.
 pub struct Date {
     day: u8,
     month: Month,
     year: u16,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 /// The month of the year
 pub enum Month {
     /// January (01)
@@ -119,14 +123,241 @@ impl Date {
         self.year % 4 == 0 && self.year % 100 != 0 || self.year % 400 == 0
     }
 
+    /// Returns the day right after `self`, rolling over month and year boundaries.
+    ///
+    /// # Example
+    /// ```
+    /// use money_monitor::units::{Date, Month};
+    ///
+    /// # fn main() -> Result<(), String> {
+    /// assert_eq!(Date::new(1, Month::September, 2025)?, Date::new(31, Month::August, 2025)?.succ());
+    /// assert_eq!(Date::new(1, Month::January, 2026)?, Date::new(31, Month::December, 2025)?.succ());
+    /// assert_eq!(Date::new(29, Month::February, 2024)?, Date::new(28, Month::February, 2024)?.succ()); // leap year
+    /// # Ok(())}
+    /// ```
+    pub fn succ(&self) -> Date {
+        if self.day < self.nb_days_in_month(self.month) {
+            Self {
+                day: self.day + 1,
+                ..self.clone()
+            }
+        } else if self.month == Month::December {
+            Self {
+                day: 1,
+                month: Month::January,
+                year: self.year + 1,
+            }
+        } else {
+            Self {
+                day: 1,
+                month: Month::from_number(self.month.number_from_month() + 1)
+                    .expect("month is not December, so its successor exists"),
+                year: self.year,
+            }
+        }
+    }
+
+    /// Returns the day right before `self`, rolling back over month and year boundaries.
+    ///
+    /// # Example
+    /// ```
+    /// use money_monitor::units::{Date, Month};
+    ///
+    /// # fn main() -> Result<(), String> {
+    /// assert_eq!(Date::new(31, Month::August, 2025)?, Date::new(1, Month::September, 2025)?.pred());
+    /// assert_eq!(Date::new(31, Month::December, 2024)?, Date::new(1, Month::January, 2025)?.pred());
+    /// assert_eq!(Date::new(29, Month::February, 2024)?, Date::new(1, Month::March, 2024)?.pred()); // leap year
+    /// # Ok(())}
+    /// ```
+    pub fn pred(&self) -> Date {
+        if self.day > 1 {
+            Self {
+                day: self.day - 1,
+                ..self.clone()
+            }
+        } else if self.month == Month::January {
+            Self {
+                day: 31,
+                month: Month::December,
+                year: self.year - 1,
+            }
+        } else {
+            let month = Month::from_number(self.month.number_from_month() - 1)
+                .expect("month is not January, so it has a predecessor");
+            Self {
+                day: self.nb_days_in_month(month),
+                month,
+                year: self.year,
+            }
+        }
+    }
+
+    /// Gets the day of the month (starts at 1).
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+    /// Gets the month.
+    pub fn month(&self) -> Month {
+        self.month
+    }
+    /// Gets the year.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Renders the date as `day month year` using the month name of the given locale.
+    ///
+    /// The default [Display](std::fmt::Display) implementation is equivalent to
+    /// rendering with [Locale::French].
+    ///
+    /// # Example
+    /// ```
+    /// use money_monitor::units::{Date, Month, Locale};
+    ///
+    /// # fn main() -> Result<(), String> {
+    /// let date = Date::new(15, Month::September, 2025)?;
+    /// assert_eq!("15 septembre 2025", date.to_string_localized(Locale::French));
+    /// assert_eq!("15 September 2025", date.to_string_localized(Locale::English));
+    /// # Ok(())}
+    /// ```
+    pub fn to_string_localized(&self, locale: Locale) -> String {
+        format!("{} {} {}", self.day, self.month.name(locale), self.year)
+    }
+
+    /// Serial day number of the date, counted from a fixed origin.
+    ///
+    /// Two serial numbers are only meaningful relative to each other, which is
+    /// exactly what [Date::days_between] needs.
+    fn serial_day(&self) -> i64 {
+        let prev = self.year as i64 - 1;
+        let leaps = prev / 4 - prev / 100 + prev / 400;
+        prev * 365 + leaps + self.day_of_year() as i64
+    }
+
+    /// Number of days from `other` to `self`.
+    ///
+    /// Positive when `self` comes after `other`, negative when it comes before,
+    /// zero when both are the same day.
+    ///
+    /// # Example
+    /// ```
+    /// use money_monitor::units::{Date, Month};
+    ///
+    /// # fn main() -> Result<(), String> {
+    /// let start = Date::new(28, Month::August, 2025)?;
+    /// let end = Date::new(2, Month::September, 2025)?;
+    /// assert_eq!(5, end.days_between(&start));
+    /// assert_eq!(-5, start.days_between(&end));
+    /// assert_eq!(366, Date::new(1, Month::January, 2025)?.days_between(&Date::new(1, Month::January, 2024)?)); // 2024 is a leap year
+    /// # Ok(())}
+    /// ```
+    pub fn days_between(&self, other: &Date) -> i64 {
+        self.serial_day() - other.serial_day()
+    }
+
+    /// Number of calendar months from the month of `other` to the month of `self`.
+    ///
+    /// Only the year and month are counted; the day of the month is ignored.
+    /// Returns `None` when `self` precedes `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use money_monitor::units::{Date, Month};
+    ///
+    /// # fn main() -> Result<(), String> {
+    /// let start = Date::new(15, Month::November, 2024)?;
+    /// let end = Date::new(3, Month::September, 2025)?;
+    /// assert_eq!(Some(10), end.months_since(&start)); // November 2024 -> September 2025
+    /// assert_eq!(None, start.months_since(&end));
+    /// # Ok(())}
+    /// ```
+    pub fn months_since(&self, other: &Date) -> Option<u32> {
+        if self < other {
+            return None;
+        }
+        let months = (self.year as i32 - other.year as i32) * 12
+            + (self.month.number_from_month() as i32 - other.month.number_from_month() as i32);
+        Some(months as u32)
+    }
+
+    /// Gives the position of the day in its year, January 1st being 1.
+    ///
+    /// # Example
+    /// ```
+    /// use money_monitor::units::{Date, Month};
+    ///
+    /// # fn main() -> Result<(), String> {
+    /// assert_eq!(1, Date::new(1, Month::January, 2025)?.day_of_year());
+    /// assert_eq!(59, Date::new(28, Month::February, 2025)?.day_of_year()); // 31 + 28
+    /// assert_eq!(60, Date::new(29, Month::February, 2024)?.day_of_year()); // leap year
+    /// # Ok(())}
+    /// ```
+    pub fn day_of_year(&self) -> u16 {
+        let mut count = self.day as u16;
+        for number in 1..self.month.number_from_month() {
+            let month = Month::from_number(number).expect("number is between 1 and 11");
+            count += self.nb_days_in_month(month) as u16;
+        }
+        count
+    }
+
+    /// Gives the day of the week on which the date falls.
+    ///
+    /// # Example
+    /// ```
+    /// use money_monitor::units::{Date, Month, Weekday};
+    ///
+    /// # fn main() -> Result<(), String> {
+    /// assert_eq!(Weekday::Monday, Date::new(15, Month::September, 2025)?.weekday());
+    /// assert_eq!(Weekday::Thursday, Date::new(1, Month::January, 2015)?.weekday());
+    /// # Ok(())}
+    /// ```
+    pub fn weekday(&self) -> Weekday {
+        let year = self.year as u32;
+        let dow_jan_1 = (year * 365 + (year - 1) / 4 - (year - 1) / 100 + (year - 1) / 400) % 7;
+        let dow = (dow_jan_1 + self.day_of_year() as u32 - 1) % 7;
+        match dow {
+            0 => Weekday::Sunday,
+            1 => Weekday::Monday,
+            2 => Weekday::Tuesday,
+            3 => Weekday::Wednesday,
+            4 => Weekday::Thursday,
+            5 => Weekday::Friday,
+            _ => Weekday::Saturday,
+        }
+    }
+
+    /// Iterates over every day in the inclusive interval `[from, to]`.
+    ///
+    /// Yields nothing when `from` is after `to`.
+    ///
+    /// # Example
+    /// ```
+    /// use money_monitor::units::{Date, Month};
+    ///
+    /// # fn main() -> Result<(), String> {
+    /// let from = Date::new(30, Month::August, 2025)?;
+    /// let to = Date::new(2, Month::September, 2025)?;
+    /// let days: Vec<_> = Date::range(from, to).collect();
+    /// assert_eq!(4, days.len());
+    /// assert_eq!(Date::new(1, Month::September, 2025)?, days[2]);
+    /// # Ok(())}
+    /// ```
+    pub fn range(from: Date, to: Date) -> impl Iterator<Item = Date> {
+        DateRange {
+            next: Some(from),
+            to,
+        }
+    }
+
     /// Indicates how many days there are in a month of the year in self.
-    /// 
+    ///
     /// Example
     /// ```
     /// use money_monitor::units::{Date, Month};
-    /// 
+    ///
     /// let date = Date::new(15, Month::September, 2025).unwrap();
-    /// 
+    ///
     /// assert_eq!(31, date.nb_days_in_month(Month::January)); // There were 31 days in January 2025
     /// assert_eq!(28, date.nb_days_in_month(Month::February)); // There were 27 days in February 2025
     /// assert_eq!(30, date.nb_days_in_month(Month::April)); // There were 30 days in April 2025
@@ -152,6 +383,26 @@ impl Date {
     }
 }
 
+impl PartialOrd for Date {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders dates chronologically: year first, then month, then day.
+impl Ord for Date {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.year
+            .cmp(&other.year)
+            .then(
+                self.month
+                    .number_from_month()
+                    .cmp(&other.month.number_from_month()),
+            )
+            .then(self.day.cmp(&other.day))
+    }
+}
+
 impl std::fmt::Display for Date {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} {} {}", self.day, self.month, self.year)
@@ -187,26 +438,204 @@ impl Month {
             _ => Err(format!("Month {number} does not exist")),
         }
     }
+
+    /// Gives the ordinal of the month in the year (January is 1, December is 12).
+    ///
+    /// This is the reverse of [Month::from_number] and is used to compare months
+    /// (and thus [Date]s) chronologically.
+    ///
+    /// # Example
+    /// ```
+    /// use money_monitor::units::Month;
+    ///
+    /// assert_eq!(9, Month::September.number_from_month());
+    /// assert_eq!(1, Month::January.number_from_month());
+    /// ```
+    pub fn number_from_month(&self) -> u8 {
+        match self {
+            Self::January => 1,
+            Self::February => 2,
+            Self::March => 3,
+            Self::April => 4,
+            Self::May => 5,
+            Self::June => 6,
+            Self::July => 7,
+            Self::August => 8,
+            Self::September => 9,
+            Self::October => 10,
+            Self::November => 11,
+            Self::December => 12,
+        }
+    }
+}
+
+impl Month {
+    /// Full name of the month in the given locale.
+    ///
+    /// # Example
+    /// ```
+    /// use money_monitor::units::{Month, Locale};
+    ///
+    /// assert_eq!("septembre", Month::September.name(Locale::French));
+    /// assert_eq!("September", Month::September.name(Locale::English));
+    /// ```
+    pub fn name(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::French => match self {
+                Self::January => "janvier",
+                Self::February => "février",
+                Self::March => "mars",
+                Self::April => "avril",
+                Self::May => "mai",
+                Self::June => "juin",
+                Self::July => "juillet",
+                Self::August => "août",
+                Self::September => "septembre",
+                Self::October => "octobre",
+                Self::November => "novembre",
+                Self::December => "décembre",
+            },
+            Locale::English => match self {
+                Self::January => "January",
+                Self::February => "February",
+                Self::March => "March",
+                Self::April => "April",
+                Self::May => "May",
+                Self::June => "June",
+                Self::July => "July",
+                Self::August => "August",
+                Self::September => "September",
+                Self::October => "October",
+                Self::November => "November",
+                Self::December => "December",
+            },
+        }
+    }
+
+    /// Parses a month written as a word, in French or in English.
+    ///
+    /// The comparison is case-insensitive and ignores accents, and common
+    /// abbreviations are accepted. French banks tend to export human-readable
+    /// month names, hence supporting both spellings.
+    ///
+    /// # Example
+    /// ```
+    /// use money_monitor::units::Month;
+    ///
+    /// assert_eq!(Ok(Month::January), Month::from_name("janvier"));
+    /// assert_eq!(Ok(Month::February), Month::from_name("Févr"));
+    /// assert_eq!(Ok(Month::February), Month::from_name("fev"));
+    /// assert_eq!(Ok(Month::January), Month::from_name("January"));
+    /// assert_eq!(Ok(Month::January), Month::from_name("Jan"));
+    /// assert!(Month::from_name("foo").is_err());
+    /// ```
+    pub fn from_name<S: AsRef<str>>(name: S) -> Result<Month, String> {
+        let normalized: String = name
+            .as_ref()
+            .trim()
+            .to_lowercase()
+            .chars()
+            .map(|c| match c {
+                'à' | 'â' | 'ä' => 'a',
+                'é' | 'è' | 'ê' | 'ë' => 'e',
+                'î' | 'ï' => 'i',
+                'ô' | 'ö' => 'o',
+                'û' | 'ü' => 'u',
+                other => other,
+            })
+            .collect();
+
+        match normalized.as_str() {
+            "jan" | "janv" | "janvier" | "january" => Ok(Self::January),
+            "fev" | "fevr" | "fevrier" | "feb" | "february" => Ok(Self::February),
+            "mar" | "mars" | "march" => Ok(Self::March),
+            "avr" | "avril" | "apr" | "april" => Ok(Self::April),
+            "mai" | "may" => Ok(Self::May),
+            "juin" | "jun" | "june" => Ok(Self::June),
+            "juil" | "juillet" | "jul" | "july" => Ok(Self::July),
+            "aou" | "aout" | "aug" | "august" => Ok(Self::August),
+            "sep" | "sept" | "septembre" | "september" => Ok(Self::September),
+            "oct" | "octobre" | "october" => Ok(Self::October),
+            "nov" | "novembre" | "november" => Ok(Self::November),
+            "dec" | "decembre" | "december" => Ok(Self::December),
+            _ => Err(format!("{} is not a known month name", name.as_ref())),
+        }
+    }
+}
+
+/// A language used to render or parse human-readable dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    /// French (the crate's default, because the author is French).
+    French,
+    /// English.
+    English,
 }
 
 /// Display in French because I am French
 impl std::fmt::Display for Month {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name(Locale::French))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A day of the week
+pub enum Weekday {
+    /// Monday
+    Monday,
+    /// Tuesday
+    Tuesday,
+    /// Wednesday
+    Wednesday,
+    /// Thursday
+    Thursday,
+    /// Friday
+    Friday,
+    /// Saturday
+    Saturday,
+    /// Sunday
+    Sunday,
+}
+
+/// Display in French because I am French
+impl std::fmt::Display for Weekday {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let word = match self {
-            Self::January => "janvier",
-            Self::February => "février",
-            Self::March => "mars",
-            Self::April => "avril",
-            Self::May => "mai",
-            Self::June => "juin",
-            Self::July => "juillet",
-            Self::August => "août",
-            Self::September => "septembre",
-            Self::October => "octobre",
-            Self::November => "novembre",
-            Self::December => "décembre",
+            Self::Monday => "lundi",
+            Self::Tuesday => "mardi",
+            Self::Wednesday => "mercredi",
+            Self::Thursday => "jeudi",
+            Self::Friday => "vendredi",
+            Self::Saturday => "samedi",
+            Self::Sunday => "dimanche",
         };
 
         write!(f, "{word}")
     }
 }
+
+/// Iterator over every day in an inclusive date interval.
+///
+/// Created by [Date::range].
+struct DateRange {
+    /// Next day to yield, or `None` once the interval has been exhausted.
+    next: Option<Date>,
+    /// Last day of the interval (inclusive).
+    to: Date,
+}
+
+impl Iterator for DateRange {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Date> {
+        let current = self.next.take()?;
+        if current > self.to {
+            return None;
+        }
+        if current < self.to {
+            self.next = Some(current.succ());
+        }
+        Some(current)
+    }
+}